@@ -0,0 +1,83 @@
+use crate::input::TypingMethod;
+use once_cell::sync::Lazy;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+pub static CONFIG_STORE: Lazy<Mutex<ConfigStore>> = Lazy::new(|| Mutex::new(ConfigStore::load()));
+
+pub struct ConfigStore {
+    pub typing_method: TypingMethod,
+    pub vietnamese_enabled: bool,
+}
+
+impl Default for ConfigStore {
+    fn default() -> Self {
+        Self {
+            typing_method: TypingMethod::Telex,
+            vietnamese_enabled: true,
+        }
+    }
+}
+
+impl ConfigStore {
+    // Reads the config file under the home directory, falling back to
+    // defaults if it's missing or malformed. Unknown keys are ignored so
+    // the format can grow (e.g. future hotkey bindings) without breaking
+    // older config files.
+    fn load() -> Self {
+        let mut store = Self::default();
+        let content = match fs::read_to_string(config_path()) {
+            Ok(content) => content,
+            Err(_) => return store,
+        };
+        for line in content.lines() {
+            let line = line.trim();
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let key = key.trim();
+            let value = value.trim();
+            match key {
+                "typing_method" => match value {
+                    "vni" => store.typing_method = TypingMethod::VNI,
+                    "telex" => store.typing_method = TypingMethod::Telex,
+                    _ => {}
+                },
+                "vietnamese_enabled" => match value {
+                    "true" => store.vietnamese_enabled = true,
+                    "false" => store.vietnamese_enabled = false,
+                    _ => {}
+                },
+                _ => {}
+            }
+        }
+        store
+    }
+
+    pub fn save(&self) {
+        let method = match self.typing_method {
+            TypingMethod::VNI => "vni",
+            TypingMethod::Telex => "telex",
+        };
+        let content = format!(
+            "typing_method = {}\nvietnamese_enabled = {}\n",
+            method, self.vietnamese_enabled
+        );
+        let path = config_path();
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        if let Ok(mut file) = fs::File::create(path) {
+            let _ = file.write_all(content.as_bytes());
+        }
+    }
+}
+
+fn config_path() -> PathBuf {
+    let home = std::env::var("HOME")
+        .or_else(|_| std::env::var("USERPROFILE"))
+        .unwrap_or_else(|_| ".".into());
+    PathBuf::from(home).join(".bogo-rs").join("config")
+}
@@ -0,0 +1,279 @@
+use once_cell::sync::Lazy;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+// Legal Vietnamese syllable onsets, tone-stripped and diacritic-stripped
+// down to plain Latin letters. "" stands for a vowel-initial syllable
+// (e.g. "oanh", "uyen").
+const ONSETS: &[&str] = &[
+    "", "b", "c", "ch", "d", "g", "gh", "gi", "h", "k", "kh", "l", "m", "n", "ng", "ngh", "nh",
+    "p", "ph", "qu", "r", "s", "t", "th", "tr", "v", "x",
+];
+
+// Legal rimes (nucleus + optional coda), including the glide nuclei
+// ("oa", "oe", "uy", "ie" for iê, "uo" for both uô and ươ) collapsed to
+// their skeleton form by `base_letter`.
+const RIMES: &[&str] = &[
+    // bare nuclei
+    "a", "e", "i", "o", "u", "y", "ua", "uy", "oa", "oe", "ie", "uo", "ue",
+    // single-vowel nucleus + coda
+    "ac", "ach", "am", "an", "ang", "anh", "ao", "ap", "at", "au", "ay", "ec", "ech", "em", "en",
+    "eng", "enh", "eo", "ep", "et", "eu", "ia", "ich", "im", "in", "inh", "ip", "it", "iu", "oc",
+    "oi", "om", "on", "ong", "ooc", "oong", "op", "ot", "uc", "ui", "um", "un", "ung", "up", "ut",
+    "uu", "ynh", "yt",
+    // ue (uê) glide nucleus + coda
+    "uech", "uenh",
+    // oa/oe glide nucleus + coda
+    "oac", "oach", "oai", "oam", "oan", "oang", "oanh", "oao", "oap", "oat", "oay", "oen", "oeo",
+    "oet", // uy glide nucleus + coda
+    "uya", "uych", "uyen", "uyet", "uynh", "uyt", "uyu",
+    // ie (iê) and uo (uô/ươ) glide nucleus + coda
+    "iec", "iem", "ien", "ieng", "iep", "iet", "ieu", "uoc", "uoi", "uom", "uon", "uong", "uop",
+    "uot", "uou", // ua glide nucleus + coda
+    "uan", "uang", "uat", "uay", // y nucleus + coda
+    "yem", "yen", "yet", "yeu",
+];
+
+struct TrieNode {
+    children: HashMap<char, TrieNode>,
+    is_terminal: bool,
+    weight: u32,
+    // Highest weight among this node and all of its descendants, kept up
+    // to date on insert so a lookup can skip a whole subtree once it can't
+    // possibly beat the results already found.
+    max_weight: u32,
+}
+
+impl TrieNode {
+    fn new() -> Self {
+        Self {
+            children: HashMap::new(),
+            is_terminal: false,
+            weight: 0,
+            max_weight: 0,
+        }
+    }
+}
+
+struct Trie {
+    root: TrieNode,
+}
+
+impl Trie {
+    fn new() -> Self {
+        Self {
+            root: TrieNode::new(),
+        }
+    }
+
+    fn insert(&mut self, word: &str, weight: u32) {
+        let mut node = &mut self.root;
+        node.max_weight = node.max_weight.max(weight);
+        for c in word.chars() {
+            node = node.children.entry(c).or_insert_with(TrieNode::new);
+            node.max_weight = node.max_weight.max(weight);
+        }
+        node.is_terminal = true;
+        node.weight = weight;
+    }
+
+    fn is_prefix(&self, prefix: &str) -> bool {
+        let mut node = &self.root;
+        for c in prefix.chars() {
+            match node.children.get(&c) {
+                Some(child) => node = child,
+                None => return false,
+            }
+        }
+        true
+    }
+
+    // Walks down to the node matching `prefix`, then collects the top
+    // `limit` terminal descendants (complete syllables) by weight. Prunes
+    // whole subtrees once they can no longer outrank what's already been
+    // found, so `limit` bounds how much of the trie gets walked, not just
+    // how many results come back.
+    fn suggestions(&self, prefix: &str, limit: usize) -> Vec<(String, u32)> {
+        if limit == 0 {
+            return Vec::new();
+        }
+        let mut node = &self.root;
+        for c in prefix.chars() {
+            match node.children.get(&c) {
+                Some(child) => node = child,
+                None => return Vec::new(),
+            }
+        }
+        let mut heap: BinaryHeap<Reverse<(u32, String)>> = BinaryHeap::new();
+        let mut word = prefix.to_string();
+        collect_top_k(node, &mut word, limit, &mut heap);
+        let mut results: Vec<(String, u32)> = heap
+            .into_iter()
+            .map(|Reverse((weight, word))| (word, weight))
+            .collect();
+        results.sort_by_key(|(_, weight)| Reverse(*weight));
+        results
+    }
+}
+
+fn collect_top_k(
+    node: &TrieNode,
+    word: &mut String,
+    limit: usize,
+    heap: &mut BinaryHeap<Reverse<(u32, String)>>,
+) {
+    let exhausted = heap.len() >= limit
+        && heap
+            .peek()
+            .is_some_and(|Reverse((worst_kept, _))| node.max_weight <= *worst_kept);
+    if exhausted {
+        return;
+    }
+    if node.is_terminal {
+        push_candidate(heap, limit, node.weight, word.clone());
+    }
+    for (c, child) in &node.children {
+        word.push(*c);
+        collect_top_k(child, word, limit, heap);
+        word.pop();
+    }
+}
+
+fn push_candidate(
+    heap: &mut BinaryHeap<Reverse<(u32, String)>>,
+    limit: usize,
+    weight: u32,
+    word: String,
+) {
+    if heap.len() < limit {
+        heap.push(Reverse((weight, word)));
+        return;
+    }
+    if heap
+        .peek()
+        .is_some_and(|Reverse((worst_kept, _))| weight <= *worst_kept)
+    {
+        return;
+    }
+    heap.pop();
+    heap.push(Reverse((weight, word)));
+}
+
+// Every legal syllable is an onset followed by a rime, so generating the
+// full cross product guarantees every real word is covered (unlike a
+// hand-picked sample, which inevitably misses combinations). This does
+// over-generate some onset/rime pairs that aren't real Vietnamese words,
+// but that only makes the validity check more lenient, never less -
+// exactly the safe direction for a heuristic that must never stop on a
+// still-extendable prefix.
+static SYLLABLE_TRIE: Lazy<Trie> = Lazy::new(|| {
+    let mut trie = Trie::new();
+    for onset in ONSETS {
+        for rime in RIMES {
+            let word = format!("{onset}{rime}");
+            // Shorter syllables tend to be more common in everyday
+            // Vietnamese, so until we have real corpus frequencies, weigh
+            // them higher.
+            let weight = 100u32.saturating_sub(word.chars().count() as u32);
+            trie.insert(&word, weight);
+        }
+    }
+    trie
+});
+
+// Strips tone marks and the remaining diacritics (circumflex, breve, horn,
+// đ-stroke) off a single Vietnamese letter, collapsing it down to its
+// plain Latin skeleton form, e.g. 'ệ' -> 'e', 'ư' -> 'u', 'đ' -> 'd'.
+fn base_letter(c: char) -> char {
+    match c {
+        'à' | 'ả' | 'ã' | 'á' | 'ạ' | 'ă' | 'ằ' | 'ẳ' | 'ẵ' | 'ắ' | 'ặ' | 'â' | 'ầ' | 'ẩ' | 'ẫ'
+        | 'ấ' | 'ậ' => 'a',
+        'è' | 'ẻ' | 'ẽ' | 'é' | 'ẹ' | 'ê' | 'ề' | 'ể' | 'ễ' | 'ế' | 'ệ' => 'e',
+        'ì' | 'ỉ' | 'ĩ' | 'í' | 'ị' => 'i',
+        'ò' | 'ỏ' | 'õ' | 'ó' | 'ọ' | 'ô' | 'ồ' | 'ổ' | 'ỗ' | 'ố' | 'ộ' | 'ơ' | 'ờ' | 'ở' | 'ỡ'
+        | 'ớ' | 'ợ' => 'o',
+        'ù' | 'ủ' | 'ũ' | 'ú' | 'ụ' | 'ư' | 'ừ' | 'ử' | 'ữ' | 'ứ' | 'ự' => 'u',
+        'ỳ' | 'ỷ' | 'ỹ' | 'ý' | 'ỵ' => 'y',
+        'đ' => 'd',
+        other => other,
+    }
+}
+
+// Normalizes a buffer down to its tone-stripped, diacritic-stripped
+// skeleton so it can be looked up in the syllable trie, e.g. "nghiêng"
+// and "nghieng" both normalize to "nghieng".
+pub fn normalize(buffer: &str) -> String {
+    buffer.to_lowercase().chars().map(base_letter).collect()
+}
+
+// Whether `prefix` (expected to already be normalized) could still grow
+// into a legal Vietnamese syllable. Returns `true` for any prefix of a
+// known syllable, even if `prefix` itself isn't a complete word yet.
+pub fn is_valid_prefix(prefix: &str) -> bool {
+    SYLLABLE_TRIE.is_prefix(prefix)
+}
+
+// Candidate syllables the given (normalized) prefix could still complete
+// to, ordered most-likely-first and capped at `limit` results.
+pub fn suggestions(prefix: &str, limit: usize) -> Vec<(String, u32)> {
+    SYLLABLE_TRIE.suggestions(prefix, limit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_prefixes_of_known_syllables() {
+        assert!(is_valid_prefix(""));
+        assert!(is_valid_prefix("ngh"));
+        assert!(is_valid_prefix("nghieng"));
+        assert!(is_valid_prefix("kho"));
+        assert!(is_valid_prefix("tr"));
+    }
+
+    #[test]
+    fn rejects_gibberish() {
+        assert!(!is_valid_prefix("strl"));
+        assert!(!is_valid_prefix("zzz"));
+    }
+
+    #[test]
+    fn normalize_strips_tones_and_diacritics() {
+        assert_eq!(normalize("nghiêng"), "nghieng");
+        assert_eq!(normalize("việt"), "viet");
+    }
+
+    #[test]
+    fn suggestions_are_ranked_by_weight_and_capped() {
+        let top = suggestions("kh", 3);
+        assert_eq!(top.len(), 3);
+        for pair in top.windows(2) {
+            assert!(pair[0].1 >= pair[1].1);
+        }
+
+        let all = suggestions("kh", usize::MAX);
+        assert!(all.len() > 3);
+        assert!(all.iter().all(|(word, _)| word.starts_with("kh")));
+    }
+
+    #[test]
+    fn suggestions_on_unknown_prefix_are_empty() {
+        assert!(suggestions("zzz", 5).is_empty());
+    }
+
+    #[test]
+    fn does_not_stop_on_real_words_with_glide_nuclei() {
+        for word in [
+            "hoan", "hoang", "toan", "loan", "xoay", "uyen", "quynh", "oanh",
+        ] {
+            for end in 1..=word.chars().count() {
+                let prefix: String = word.chars().take(end).collect();
+                assert!(
+                    is_valid_prefix(&prefix),
+                    "{prefix:?} should be a valid prefix"
+                );
+            }
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use crate::config::CONFIG_STORE;
+use crate::dictionary;
 use druid::Data;
 use log::debug;
 use once_cell::sync::Lazy;
@@ -29,25 +31,63 @@ pub enum TypingMethod {
     Telex,
 }
 
+// Tunable tolerances for when `InputState` gives up tracking a word. These
+// default to the values that used to be hardcoded as `MAX_POSSIBLE_WORD_LENGTH`
+// and `MAX_DUPLICATE_LENGTH`, but can be loosened (e.g. for stylized typing)
+// without recompiling.
+#[derive(Clone, Copy)]
+pub struct TrackingConfig {
+    pub max_word_length: usize,
+    pub max_duplicate_length: usize,
+}
+
+impl Default for TrackingConfig {
+    fn default() -> Self {
+        Self {
+            max_word_length: MAX_POSSIBLE_WORD_LENGTH,
+            max_duplicate_length: MAX_DUPLICATE_LENGTH,
+        }
+    }
+}
+
 pub struct InputState {
     buffer: String,
     display_buffer: String,
     method: TypingMethod,
     enabled: bool,
     should_track: bool,
+    tracking_config: TrackingConfig,
 }
 
 impl InputState {
     pub fn new() -> Self {
+        let config = CONFIG_STORE.lock().unwrap();
         Self {
             buffer: String::new(),
             display_buffer: String::new(),
-            method: TypingMethod::Telex,
-            enabled: true,
+            method: config.typing_method,
+            enabled: config.vietnamese_enabled,
             should_track: true,
+            tracking_config: TrackingConfig::default(),
         }
     }
 
+    pub fn get_tracking_config(&self) -> TrackingConfig {
+        self.tracking_config
+    }
+
+    pub fn set_tracking_config(&mut self, tracking_config: TrackingConfig) {
+        self.tracking_config = tracking_config;
+        self.new_word();
+    }
+
+    // Candidate syllables the current buffer could still complete to,
+    // ranked most-likely-first and capped at `limit` results. Handy as a
+    // cheap autocomplete/suggestion source for downstream UI code.
+    pub fn suggestions(&self, limit: usize) -> Vec<(String, u32)> {
+        dictionary::suggestions(&dictionary::normalize(&self.transform_keys()), limit)
+    }
+
     pub fn is_enabled(&self) -> bool {
         self.enabled
     }
@@ -74,11 +114,19 @@ impl InputState {
 
     pub fn toggle_vietnamese(&mut self) {
         self.enabled = !self.enabled;
+        let mut config = CONFIG_STORE.lock().unwrap();
+        config.vietnamese_enabled = self.enabled;
+        config.save();
+        drop(config);
         self.new_word();
     }
 
     pub fn set_method(&mut self, method: TypingMethod) {
         self.method = method;
+        let mut config = CONFIG_STORE.lock().unwrap();
+        config.typing_method = method;
+        config.save();
+        drop(config);
         self.new_word();
     }
 
@@ -124,7 +172,7 @@ impl InputState {
     }
 
     pub fn push(&mut self, c: char) {
-        if self.buffer.len() <= MAX_POSSIBLE_WORD_LENGTH {
+        if self.buffer.chars().count() <= self.tracking_config.max_word_length {
             self.buffer.push(c);
             self.display_buffer.push(c);
             debug!(
@@ -155,12 +203,18 @@ impl InputState {
     // implement it anyway. we'll figure out where to put these
     // later on.
     pub fn should_stop_tracking(&mut self) -> bool {
-        let len = self.buffer.len();
-        if len >= MAX_DUPLICATE_LENGTH {
-            let buf = &self.buffer[len - MAX_DUPLICATE_LENGTH..];
-            let first = buf.chars().nth(0).unwrap();
-            return buf.chars().all(|c| c == first);
+        let max_duplicate_length = self.tracking_config.max_duplicate_length;
+        let chars: Vec<char> = self.buffer.chars().collect();
+        if chars.len() >= max_duplicate_length {
+            let tail = &chars[chars.len() - max_duplicate_length..];
+            let first = tail[0];
+            if tail.iter().all(|c| *c == first) {
+                return true;
+            }
         }
-        return false;
+        // `buffer` holds raw telex/vni keystrokes (tone and modifier keys,
+        // doubled vowels), not accented text, so the dictionary check needs
+        // to run against the transformed output instead of the raw buffer.
+        !dictionary::is_valid_prefix(&dictionary::normalize(&self.transform_keys()))
     }
 }